@@ -12,7 +12,8 @@ pub enum Command {
 pub enum Status {
     Active = 0,
     Inactive = 1,
-    Unknown, // This variant is required
+    #[fallback]
+    Unknown,
 }
 
 fn main() {
@@ -4,7 +4,9 @@
 //! ## Features
 //!
 //! - `#[derive(FromReprAsOption)]`: Safely converts an integer to an enum, returning an Option<Self> if the value is valid.
-//! - `#[derive(FromReprWithUnknown)]`: For infallible conversions that require an `Unknown` variant as a fallback.
+//! - `#[derive(FromReprWithUnknown)]`: For infallible conversions that require a designated fallback variant.
+//! - `#[derive(IntoRepr)]`: The reverse conversion, from an enum back to its integer representation.
+//! - `#[derive(TryFromRepr)]`: A fallible conversion that returns a typed error describing the invalid value.
 //! - `#[enum_with_hex_docs]`: An attribute macro that adds documentation comments to enum variants, displaying both their hexadecimal and decimal values.
 
 extern crate proc_macro;
@@ -14,16 +16,22 @@ use syn::parse_macro_input;
 
 mod macros;
 
-/// Derives an `impl From<T>` for an enum that has an `Unknown` variant.
+/// Derives an `impl From<T>` for an enum that has a designated fallback variant.
 ///
 /// This macro provides an infallible conversion from an integer type `T`
-/// by falling back to an `Enum::Unknown` variant if the integer does not
-/// match any other variant.
+/// by falling back to the variant marked `#[fallback]` (or `#[default]`)
+/// if the integer does not match any other variant.
 ///
 /// # Requirements
 ///
-/// 1. The enum must have a `#[repr(T)]` attribute with an integer type `T`.
-/// 2. The enum MUST have a variant named `Unknown`.
+/// 1. The `#[repr(T)]` attribute is optional. If present, its integer type
+///    is used as `T`. If absent, `T` is inferred as the smallest integer
+///    type that fits every variant's discriminant.
+/// 2. Exactly one variant must be marked `#[fallback]` (or `#[default]`).
+///
+/// A non-fallback variant may also carry `#[alternatives(2, 3, 0x10)]` so
+/// that several integer values all map to it, which is useful for legacy
+/// wire formats where multiple codes mean the same thing.
 ///
 /// # Example
 ///
@@ -35,7 +43,8 @@ mod macros;
 /// pub enum LegacyStatus {
 ///     Active = 0,
 ///     Inactive = 1,
-///     Unknown, // Required fallback variant
+///     #[fallback]
+///     Unknown,
 /// }
 ///
 /// // The macro generates an `impl From<u8> for LegacyStatus` block.
@@ -47,7 +56,7 @@ mod macros;
 /// let status2 = LegacyStatus::from(0u8);
 /// assert_eq!(status2, LegacyStatus::Active);
 /// ```
-#[proc_macro_derive(FromReprWithUnknown)]
+#[proc_macro_derive(FromReprWithUnknown, attributes(fallback, default, alternatives))]
 pub fn from_repr_with_unknown_derive(input: TokenStream) -> TokenStream {
     // Parse the input tokens into a syntax tree
     let ast = parse_macro_input!(input);
@@ -62,8 +71,9 @@ pub fn from_repr_with_unknown_derive(input: TokenStream) -> TokenStream {
 ///
 /// # Requirements
 ///
-/// The enum must have a `#[repr(...)]` attribute with an integer type,
-/// for example `#[repr(u8)]`.
+/// The `#[repr(...)]` attribute is optional. If present, its integer type
+/// is used as `T`, for example `#[repr(u8)]`. If absent, `T` is inferred
+/// as the smallest integer type that fits every variant's discriminant.
 ///
 /// # Example
 ///
@@ -103,6 +113,73 @@ pub fn from_repr_as_option_derive(input: TokenStream) -> TokenStream {
     macros::from_repr_as_option_derive_impl(&ast)
 }
 
+/// Derives the reverse conversion from an enum back to its integer representation.
+///
+/// This is the complement of [`macro@FromReprAsOption`]: instead of building an enum
+/// from an integer, it derives a `pub const fn as_repr(&self) -> T` method plus an
+/// `impl From<Enum> for T`, where `T` is the integer type specified by the
+/// `#[repr(T)]` attribute (or inferred from the variants' discriminants if absent).
+///
+/// # Example
+///
+/// ```rust
+/// use macrokit::IntoRepr;
+///
+/// #[derive(Debug, PartialEq, IntoRepr)]
+/// #[repr(u16)]
+/// pub enum Command {
+///     Reset = 0x0100,
+///     Read = 0x0200,
+///     Write = 0x0300,
+/// }
+///
+/// assert_eq!(Command::Read.as_repr(), 0x0200);
+/// assert_eq!(u16::from(Command::Write), 0x0300);
+/// ```
+#[proc_macro_derive(IntoRepr)]
+pub fn into_repr_derive(input: TokenStream) -> TokenStream {
+    // Parse the input tokens into a syntax tree
+    let ast = parse_macro_input!(input);
+    macros::into_repr_derive_impl(&ast)
+}
+
+/// Derives a fallible `TryFrom<T>` conversion for an enum, returning a typed error.
+///
+/// Unlike [`macro@FromReprAsOption`] (which returns `Option<Self>`) or
+/// [`macro@FromReprWithUnknown`] (which falls back to a designated variant), this
+/// derive is for callers that want to propagate a descriptive error with `?` when an
+/// integer from untrusted input doesn't match any variant. It generates an
+/// `impl TryFrom<T> for Enum` plus a dedicated `EnumTryFromReprError` type carrying
+/// the offending value, implementing `std::error::Error` and `Display`.
+///
+/// # Requirements
+///
+/// The `#[repr(T)]` attribute is optional. If present, its integer type is used as
+/// `T`. If absent, `T` is inferred as the smallest integer type that fits every
+/// variant's discriminant.
+///
+/// # Example
+///
+/// ```rust
+/// use macrokit::TryFromRepr;
+///
+/// #[derive(Debug, PartialEq, TryFromRepr)]
+/// #[repr(u8)]
+/// pub enum Command {
+///     Read = 1,
+///     Write = 2,
+/// }
+///
+/// assert_eq!(Command::try_from(1), Ok(Command::Read));
+/// assert_eq!(Command::try_from(99).unwrap_err().value, 99);
+/// ```
+#[proc_macro_derive(TryFromRepr)]
+pub fn try_from_repr_derive(input: TokenStream) -> TokenStream {
+    // Parse the input tokens into a syntax tree
+    let ast = parse_macro_input!(input);
+    macros::try_from_repr_derive_impl(&ast)
+}
+
 /// An attribute macro that enriches enum variants with documentation comments
 /// displaying their hexadecimal and decimal values.
 ///
@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+
+use syn::punctuated::Punctuated;
+use syn::token::Comma;
+use syn::{Expr, Ident, Lit, UnOp, Variant};
+
+/// Computes the effective discriminant of every variant in declaration order.
+///
+/// Follows the same rules as a plain Rust enum: the first variant starts at
+/// `0`, each subsequent variant is the previous value plus one, and an
+/// explicit `= N` literal resets the counter. This lets callers work with
+/// the resulting integer directly instead of relying on `Variant as T`,
+/// which only compiles for fieldless variants.
+pub(crate) fn compute_discriminants(variants: &Punctuated<Variant, Comma>) -> Vec<i128> {
+    let mut next_val: i128 = 0;
+    variants
+        .iter()
+        .map(|variant| {
+            let val = match &variant.discriminant {
+                Some((_, expr)) => literal_discriminant(expr).unwrap_or(next_val),
+                None => next_val,
+            };
+            next_val = val + 1;
+            val
+        })
+        .collect()
+}
+
+/// Parses an explicit discriminant expression into its integer value.
+///
+/// Handles plain integer literals (`= 1`) as well as their negation
+/// (`= -1`), which `syn` represents as `Expr::Unary` wrapping an
+/// `Expr::Lit` rather than as a literal itself.
+fn literal_discriminant(expr: &Expr) -> Option<i128> {
+    match expr {
+        Expr::Lit(expr_lit) => match &expr_lit.lit {
+            Lit::Int(lit_int) => lit_int.base10_parse::<i128>().ok(),
+            _ => None,
+        },
+        Expr::Unary(expr_unary) if matches!(expr_unary.op, UnOp::Neg(_)) => {
+            literal_discriminant(&expr_unary.expr).map(|val| -val)
+        }
+        _ => None,
+    }
+}
+
+/// Validates that no two entries collide on the same discriminant value, and
+/// that every value fits within `repr_type`'s range.
+///
+/// Takes `(variant, value)` pairs rather than a 1:1 `variants`/`discriminants`
+/// zip so callers can also validate extra values that aren't a variant's own
+/// discriminant, such as `FromReprWithUnknown`'s `#[alternatives(...)]`.
+///
+/// Shared by every derive that matches on a variant's integer value, plus
+/// `enum_with_hex_docs`, so a mistake is caught once at compile time instead
+/// of silently producing an unreachable match arm or a truncated value.
+pub(crate) fn validate_discriminants(
+    entries: &[(&Variant, i128)],
+    repr_type: &Ident,
+) -> syn::Result<()> {
+    let mut seen: HashMap<i128, &Variant> = HashMap::new();
+    for (variant, value) in entries {
+        if let Some(previous) = seen.insert(*value, variant) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                format!("discriminant `{value}` collides with variant `{}`", previous.ident),
+            ));
+        }
+    }
+
+    if let Some((min, max)) = repr_bounds(repr_type) {
+        for (variant, value) in entries {
+            if *value < min || *value > max {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    format!(
+                        "discriminant `{value}` does not fit in `{repr_type}` (expected {min}..={max})"
+                    ),
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the inclusive `(min, max)` range representable by `repr_type`.
+///
+/// Returns `None` for `usize`/`isize`, whose width depends on the target, so
+/// out-of-range discriminants for those aren't checked.
+fn repr_bounds(repr_type: &Ident) -> Option<(i128, i128)> {
+    match repr_type.to_string().as_str() {
+        "u8" => Some((u8::MIN as i128, u8::MAX as i128)),
+        "u16" => Some((u16::MIN as i128, u16::MAX as i128)),
+        "u32" => Some((u32::MIN as i128, u32::MAX as i128)),
+        "u64" => Some((u64::MIN as i128, u64::MAX as i128)),
+        "i8" => Some((i8::MIN as i128, i8::MAX as i128)),
+        "i16" => Some((i16::MIN as i128, i16::MAX as i128)),
+        "i32" => Some((i32::MIN as i128, i32::MAX as i128)),
+        "i64" => Some((i64::MIN as i128, i64::MAX as i128)),
+        _ => None,
+    }
+}
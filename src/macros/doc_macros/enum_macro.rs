@@ -1,8 +1,9 @@
 use proc_macro::Span;
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{Attribute, Ident};
-use syn::{Expr, ItemEnum};
+use syn::{Attribute, Ident, ItemEnum};
+
+use crate::macros::discriminant::{compute_discriminants, validate_discriminants};
 
 /// Extracts the integer type from a `#[repr(...)]` attribute.
 ///
@@ -66,6 +67,13 @@ fn extract_repr(attrs: &[Attribute]) -> Option<Ident> {
 ///
 /// A `TokenStream` representing the modified enum.
 pub fn generate_enum_with_docs(input: ItemEnum) -> TokenStream {
+    match try_generate_enum_with_docs(input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn try_generate_enum_with_docs(input: ItemEnum) -> syn::Result<proc_macro2::TokenStream> {
     let enum_ident = &input.ident;
     let vis = &input.vis;
     let generics = &input.generics;
@@ -75,65 +83,31 @@ pub fn generate_enum_with_docs(input: ItemEnum) -> TokenStream {
     let repr_ty = extract_repr(&input.attrs)
         .unwrap_or_else(|| syn::Ident::new("isize", proc_macro::Span::call_site().into()));
 
-    let mut next_val: Option<i128> = Some(0);
-    let mut variants = Vec::new();
+    let discriminants = compute_discriminants(&input.variants);
+    let entries: Vec<(&syn::Variant, i128)> =
+        input.variants.iter().zip(discriminants.iter().copied()).collect();
+    validate_discriminants(&entries, &repr_ty)?;
 
-    for variant in input.variants {
+    let variants = input.variants.iter().zip(discriminants.iter()).map(|(variant, val)| {
         let ident = &variant.ident;
-        let mut val: Option<i128> = None;
-
-        // If the variant has an explicit discriminant, parse it.
-        if let Some((_, expr)) = &variant.discriminant {
-            if let Expr::Lit(expr_lit) = expr {
-                if let syn::Lit::Int(lit_int) = &expr_lit.lit {
-                    if let Ok(v) = lit_int.base10_parse::<i128>() {
-                        next_val = Some(v + 1);
-                        val = Some(v);
-                    }
-                }
-            }
-        }
-
-        // If no explicit discriminant, use the auto-incremented value.
-        if val.is_none() {
-            if let Some(v) = next_val {
-                val = Some(v);
-                next_val = Some(v + 1);
-            }
-        }
 
         // Build the new doc string with hex and decimal values.
-        let doc = if let Some(v) = val {
-            let hex = format!("{:#X}", v);
-            let dec = format!("{}", v);
-            format!("{} = {} ({})", ident, hex, dec)
-        } else {
-            format!("{} (unknown)", ident)
-        };
+        let hex = format!("{:#X}", val);
+        let doc = format!("{} = {} ({})", ident, hex, val);
 
         let attrs = &variant.attrs;
-        if let Some(v) = val {
-            variants.push(quote! {
-                #( #attrs )*
-                #[doc = #doc]
-                #ident = #v as #repr_ty
-            });
-        } else {
-            variants.push(quote! {
-                #( #attrs )*
-                #[doc = #doc]
-                #ident
-            });
+        quote! {
+            #( #attrs )*
+            #[doc = #doc]
+            #ident = #val as #repr_ty
         }
-    }
+    });
 
     // Reconstruct the enum with the new variants.
-    let expanded = quote! {
+    Ok(quote! {
         #( #attrs )*
         #vis enum #enum_ident #generics {
             #( #variants, )*
         }
-    };
-
-    expanded.into()
+    })
 }
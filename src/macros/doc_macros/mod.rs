@@ -0,0 +1,3 @@
+mod enum_macro;
+
+pub use enum_macro::generate_enum_with_docs;
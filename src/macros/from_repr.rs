@@ -1,39 +1,78 @@
 use proc_macro::TokenStream;
+use proc_macro2::Literal;
 use quote::quote;
-use syn::{Data, DeriveInput, Ident};
+use syn::punctuated::Punctuated;
+use syn::token::Comma;
+use syn::{Data, DeriveInput, Fields, Ident, Lit, Variant};
+
+use super::discriminant::{compute_discriminants, validate_discriminants};
 
 /// The core implementation logic for the `FromWithUnknown` derive macro.
 ///
 /// This function takes the parsed abstract syntax tree (AST) of an enum
 /// and generates the token stream for an `impl From<T> for Enum` block.
 pub fn from_repr_with_unknown_derive_impl(ast: &DeriveInput) -> TokenStream {
+    match try_from_repr_with_unknown_derive_impl(ast) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn try_from_repr_with_unknown_derive_impl(
+    ast: &DeriveInput,
+) -> syn::Result<proc_macro2::TokenStream> {
     // Get the name of the enum we're implementing the trait for
     let enum_name = &ast.ident;
 
-    // Find the integer type from the `#[repr(...)]` attribute (e.g., u8, u16)
-    let repr_type = match get_repr_type(ast) {
-        Some(t) => t,
-        None => panic!("FromWithUnknown requires a #[repr(...)] attribute (e.g., #[repr(u8)])"),
-    };
-
     // Ensure the input is an enum
     let variants = match &ast.data {
         Data::Enum(data_enum) => &data_enum.variants,
         _ => panic!("FromWithUnknown can only be used on enums"),
     };
 
-    // Generate a match arm for each variant, e.g., `v if v == MyEnum::Variant as u8 => MyEnum::Variant,`
-    // We explicitly skip the `Unknown` variant to let it be the fallback case.
-    let match_arms = variants.iter().filter_map(|variant| {
-        let variant_name = &variant.ident;
-        if variant_name == "Unknown" {
-            None // Skip the `Unknown` variant
-        } else {
-            Some(quote! {
-                v if v == #enum_name::#variant_name as #repr_type => #enum_name::#variant_name,
-            })
+    let fallback_index = find_fallback_variant(variants)?;
+    let fallback_name = &variants[fallback_index].ident;
+    let discriminants = compute_discriminants(variants);
+    // Use the `#[repr(...)]` attribute if present, otherwise infer the
+    // smallest integer type that fits every variant's discriminant.
+    let repr_type = resolve_repr_type(ast, &discriminants);
+
+    // Collect each variant's discriminant plus its `#[alternatives(...)]` values
+    // (if any) so collisions between the two are caught, not just between
+    // variants' own discriminants.
+    let mut entries: Vec<(&Variant, i128)> = Vec::new();
+    let mut alternatives_by_variant = Vec::with_capacity(variants.len());
+    for (variant, discriminant) in variants.iter().zip(discriminants.iter()) {
+        entries.push((variant, *discriminant));
+        let alternatives = parse_alternatives(variant)?;
+        for alternative in &alternatives {
+            entries.push((variant, *alternative));
         }
-    });
+        alternatives_by_variant.push(alternatives);
+    }
+    validate_discriminants(&entries, &repr_type)?;
+
+    // Generate a match arm for each variant, e.g., `v if v == 0 as u8 => MyEnum::Variant,`,
+    // plus one extra arm per `#[alternatives(...)]` value. The fallback variant is skipped
+    // so it's only ever produced by the final wildcard arm.
+    let mut match_arms = Vec::new();
+    for (index, (variant, discriminant)) in variants.iter().zip(discriminants.iter()).enumerate()
+    {
+        if index == fallback_index {
+            continue;
+        }
+        let value = Literal::i128_unsuffixed(*discriminant);
+        let construction = construct_variant(enum_name, variant);
+        match_arms.push(quote! {
+            v if v == #value as #repr_type => #construction,
+        });
+        for alternative in &alternatives_by_variant[index] {
+            let alt_value = Literal::i128_unsuffixed(*alternative);
+            match_arms.push(quote! {
+                v if v == #alt_value as #repr_type => #construction,
+            });
+        }
+    }
 
     // Build the `impl From<...>` block
     let generated_impl = quote! {
@@ -41,14 +80,68 @@ pub fn from_repr_with_unknown_derive_impl(ast: &DeriveInput) -> TokenStream {
             fn from(value: #repr_type) -> Self {
                 match value {
                     #( #match_arms )*
-                    _ => #enum_name::Unknown,
+                    _ => #enum_name::#fallback_name,
                 }
             }
         }
     };
 
-    // Return the generated code
-    generated_impl.into()
+    Ok(generated_impl)
+}
+
+/// Finds the variant marked as the catch-all fallback with `#[fallback]` or
+/// `#[default]`.
+///
+/// Errors at compile time when no variant is marked, or when more than one
+/// is, since the generated `From` impl needs exactly one fallback arm.
+fn find_fallback_variant(variants: &Punctuated<Variant, Comma>) -> syn::Result<usize> {
+    let mut found: Option<usize> = None;
+    for (index, variant) in variants.iter().enumerate() {
+        if is_fallback_variant(variant) {
+            if found.is_some() {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    "only one variant may be marked `#[fallback]` (or `#[default]`)",
+                ));
+            }
+            found = Some(index);
+        }
+    }
+    found.ok_or_else(|| {
+        syn::Error::new_spanned(
+            variants,
+            "FromReprWithUnknown requires exactly one variant marked `#[fallback]` (or `#[default]`)",
+        )
+    })
+}
+
+fn is_fallback_variant(variant: &Variant) -> bool {
+    variant
+        .attrs
+        .iter()
+        .any(|attr| attr.path().is_ident("fallback") || attr.path().is_ident("default"))
+}
+
+/// Parses a variant's `#[alternatives(2, 3, 0x10)]` attribute, if present,
+/// into the list of extra integer values that should also map to it.
+fn parse_alternatives(variant: &Variant) -> syn::Result<Vec<i128>> {
+    for attr in &variant.attrs {
+        if attr.path().is_ident("alternatives") {
+            let literals =
+                attr.parse_args_with(Punctuated::<Lit, Comma>::parse_terminated)?;
+            return literals
+                .iter()
+                .map(|lit| match lit {
+                    Lit::Int(lit_int) => lit_int.base10_parse::<i128>(),
+                    _ => Err(syn::Error::new_spanned(
+                        lit,
+                        "#[alternatives(...)] only accepts integer literals",
+                    )),
+                })
+                .collect();
+        }
+    }
+    Ok(Vec::new())
 }
 
 /// The core implementation logic for the `FromReprAsOption` derive macro.
@@ -57,34 +150,50 @@ pub fn from_repr_with_unknown_derive_impl(ast: &DeriveInput) -> TokenStream {
 /// and generates the token stream for an `impl` block containing the
 /// `from_repr` function.
 pub fn from_repr_as_option_derive_impl(ast: &DeriveInput) -> TokenStream {
-    let enum_name = &ast.ident;
+    match try_from_repr_as_option_derive_impl(ast) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
 
-    let repr_type = match get_repr_type(ast) {
-        Some(t) => t,
-        None => panic!("FromReprAsOption requires a #[repr(...)] attribute (e.g., #[repr(u8)])"),
-    };
+fn try_from_repr_as_option_derive_impl(ast: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let enum_name = &ast.ident;
 
     let variants = match &ast.data {
         Data::Enum(data_enum) => &data_enum.variants,
         _ => panic!("FromReprAsOption can only be used on enums"),
     };
 
+    let discriminants = compute_discriminants(variants);
+    // Use the `#[repr(...)]` attribute if present, otherwise infer the
+    // smallest integer type that fits every variant's discriminant.
+    let repr_type = resolve_repr_type(ast, &discriminants);
+    let entries: Vec<(&Variant, i128)> =
+        variants.iter().zip(discriminants.iter().copied()).collect();
+    validate_discriminants(&entries, &repr_type)?;
+
     // Generate a match arm for each variant that returns Some(Self::Variant)
-    let match_arms = variants.iter().map(|variant| {
-        let variant_name = &variant.ident;
+    let match_arms = variants.iter().zip(discriminants.iter()).map(|(variant, discriminant)| {
+        let value = Literal::i128_unsuffixed(*discriminant);
+        let construction = construct_variant(enum_name, variant);
         quote! {
-            // This creates arms like: `v if v == MyEnum::VariantA as u8 => Some(MyEnum::VariantA),`
-            v if v == #enum_name::#variant_name as #repr_type => Some(#enum_name::#variant_name),
+            // This creates arms like: `v if v == 0 as u8 => Some(MyEnum::VariantA),`
+            v if v == #value as #repr_type => Some(#construction),
         }
     });
 
+    // `Default::default()` isn't callable in a const fn, so only fieldless
+    // enums (whose variants need no construction) get a const `from_repr`.
+    let all_fieldless = variants.iter().all(|variant| matches!(variant.fields, Fields::Unit));
+    let constness = if all_fieldless { quote! { const } } else { quote! {} };
+
     // Build an `impl` block with a `from_repr` function that returns an Option<Self>
     let generated_impl = quote! {
         impl #enum_name {
             /// Creates an enum from its integer representation.
             ///
             /// Returns `None` if the integer does not match any variant.
-            pub fn from_repr(value: #repr_type) -> Option<Self> {
+            pub #constness fn from_repr(value: #repr_type) -> Option<Self> {
                 match value {
                     #( #match_arms )*
                     _ => None,
@@ -93,7 +202,208 @@ pub fn from_repr_as_option_derive_impl(ast: &DeriveInput) -> TokenStream {
         }
     };
 
-    generated_impl.into()
+    Ok(generated_impl)
+}
+
+/// The core implementation logic for the `IntoRepr` derive macro.
+///
+/// This function takes the parsed abstract syntax tree (AST) of an enum
+/// and generates the token stream for an `impl` block containing the
+/// `as_repr` method, plus the complementary `impl From<Enum> for T`.
+pub fn into_repr_derive_impl(ast: &DeriveInput) -> TokenStream {
+    match try_into_repr_derive_impl(ast) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn try_into_repr_derive_impl(ast: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let enum_name = &ast.ident;
+
+    let variants = match &ast.data {
+        Data::Enum(data_enum) => &data_enum.variants,
+        _ => panic!("IntoRepr can only be used on enums"),
+    };
+
+    let discriminants = compute_discriminants(variants);
+    let repr_type = resolve_repr_type(ast, &discriminants);
+    let entries: Vec<(&Variant, i128)> =
+        variants.iter().zip(discriminants.iter().copied()).collect();
+    validate_discriminants(&entries, &repr_type)?;
+
+    // Match on each variant's pattern (ignoring any fields with `..`) and
+    // return its pre-computed discriminant. Since no field is bound or
+    // moved, this works uniformly for fieldless and data-carrying variants.
+    let match_arms = variants.iter().zip(discriminants.iter()).map(|(variant, discriminant)| {
+        let pattern = variant_pattern(enum_name, variant);
+        let value = Literal::i128_unsuffixed(*discriminant);
+        quote! {
+            #pattern => #value as #repr_type,
+        }
+    });
+
+    let generated_impl = quote! {
+        impl #enum_name {
+            /// Returns the integer representation of this variant.
+            pub const fn as_repr(&self) -> #repr_type {
+                match self {
+                    #( #match_arms )*
+                }
+            }
+        }
+
+        impl From<#enum_name> for #repr_type {
+            fn from(value: #enum_name) -> Self {
+                value.as_repr()
+            }
+        }
+    };
+
+    Ok(generated_impl)
+}
+
+/// The core implementation logic for the `TryFromRepr` derive macro.
+///
+/// This function takes the parsed abstract syntax tree (AST) of an enum
+/// and generates the token stream for an `impl TryFrom<T> for Enum` block,
+/// along with a dedicated error type carrying the offending value.
+pub fn try_from_repr_derive_impl(ast: &DeriveInput) -> TokenStream {
+    match try_from_repr_impl(ast) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn try_from_repr_impl(ast: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let enum_name = &ast.ident;
+
+    let variants = match &ast.data {
+        Data::Enum(data_enum) => &data_enum.variants,
+        _ => panic!("TryFromRepr can only be used on enums"),
+    };
+
+    let discriminants = compute_discriminants(variants);
+    // Use the `#[repr(...)]` attribute if present, otherwise infer the
+    // smallest integer type that fits every variant's discriminant.
+    let repr_type = resolve_repr_type(ast, &discriminants);
+    let entries: Vec<(&Variant, i128)> =
+        variants.iter().zip(discriminants.iter().copied()).collect();
+    validate_discriminants(&entries, &repr_type)?;
+
+    let match_arms = variants.iter().zip(discriminants.iter()).map(|(variant, discriminant)| {
+        let value = Literal::i128_unsuffixed(*discriminant);
+        let construction = construct_variant(enum_name, variant);
+        quote! {
+            v if v == #value as #repr_type => Ok(#construction),
+        }
+    });
+
+    let error_name = quote::format_ident!("{}TryFromReprError", enum_name);
+    let error_doc =
+        format!("The error returned when an integer doesn't match any variant of `{enum_name}`.");
+
+    let generated_impl = quote! {
+        #[doc = #error_doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct #error_name {
+            /// The integer value that didn't match any variant.
+            pub value: #repr_type,
+        }
+
+        impl std::fmt::Display for #error_name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "invalid discriminant {} for {}", self.value, stringify!(#enum_name))
+            }
+        }
+
+        impl std::error::Error for #error_name {}
+
+        impl TryFrom<#repr_type> for #enum_name {
+            type Error = #error_name;
+
+            fn try_from(value: #repr_type) -> Result<Self, Self::Error> {
+                match value {
+                    #( #match_arms )*
+                    _ => Err(#error_name { value }),
+                }
+            }
+        }
+    };
+
+    Ok(generated_impl)
+}
+
+/// Builds the match pattern that matches a variant without binding its
+/// fields, e.g. `Enum::Variant`, `Enum::Variant(..)`, `Enum::Variant { .. }`.
+fn variant_pattern(enum_name: &Ident, variant: &Variant) -> proc_macro2::TokenStream {
+    let variant_name = &variant.ident;
+    match &variant.fields {
+        Fields::Unit => quote! { #enum_name::#variant_name },
+        Fields::Unnamed(_) => quote! { #enum_name::#variant_name(..) },
+        Fields::Named(_) => quote! { #enum_name::#variant_name { .. } },
+    }
+}
+
+/// Builds the expression that constructs a matched variant.
+///
+/// Fieldless variants are built as a bare path. Variants that carry data
+/// are built with each field set to `Default::default()`, since the
+/// discriminant alone can't tell us what the payload should be.
+fn construct_variant(enum_name: &Ident, variant: &Variant) -> proc_macro2::TokenStream {
+    let variant_name = &variant.ident;
+    match &variant.fields {
+        Fields::Unit => quote! { #enum_name::#variant_name },
+        Fields::Unnamed(fields) => {
+            let defaults = fields.unnamed.iter().map(|_| quote! { Default::default() });
+            quote! { #enum_name::#variant_name(#( #defaults ),*) }
+        }
+        Fields::Named(fields) => {
+            let inits = fields.named.iter().map(|f| {
+                let field_name = f.ident.as_ref().expect("named field has an identifier");
+                quote! { #field_name: Default::default() }
+            });
+            quote! { #enum_name::#variant_name { #( #inits ),* } }
+        }
+    }
+}
+
+/// Determines the integer representation type to convert against.
+///
+/// Uses the enum's explicit `#[repr(T)]` attribute when present. Otherwise,
+/// infers the smallest primitive (`u8`/`i8` up through `u64`/`i64`) that
+/// fits every discriminant in `discriminants`, the way enumn does for plain
+/// enums without a repr annotation.
+fn resolve_repr_type(ast: &DeriveInput, discriminants: &[i128]) -> Ident {
+    get_repr_type(ast).unwrap_or_else(|| infer_smallest_repr(discriminants))
+}
+
+/// Picks the smallest signed or unsigned primitive that fits every value in
+/// `discriminants`. Defaults to `u8` for an enum with no variants.
+fn infer_smallest_repr(discriminants: &[i128]) -> Ident {
+    let min = discriminants.iter().copied().min().unwrap_or(0);
+    let max = discriminants.iter().copied().max().unwrap_or(0);
+
+    let name = if min >= 0 {
+        if max <= u8::MAX as i128 {
+            "u8"
+        } else if max <= u16::MAX as i128 {
+            "u16"
+        } else if max <= u32::MAX as i128 {
+            "u32"
+        } else {
+            "u64"
+        }
+    } else if min >= i8::MIN as i128 && max <= i8::MAX as i128 {
+        "i8"
+    } else if min >= i16::MIN as i128 && max <= i16::MAX as i128 {
+        "i16"
+    } else if min >= i32::MIN as i128 && max <= i32::MAX as i128 {
+        "i32"
+    } else {
+        "i64"
+    };
+
+    Ident::new(name, proc_macro2::Span::call_site())
 }
 
 /// A helper function to find and parse the `#[repr(...)]` attribute from an enum.
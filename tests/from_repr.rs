@@ -14,6 +14,24 @@ fn test_from_repr_as_option() {
     assert_eq!(Command::from_repr(3), None);
 }
 
+#[test]
+fn test_from_repr_as_option_with_negative_discriminant() {
+    use macrokit::FromReprAsOption;
+
+    #[derive(Debug, PartialEq, FromReprAsOption)]
+    #[repr(i8)]
+    pub enum Temp {
+        Cold = -1,
+        Zero = 0,
+        Hot = 1,
+    }
+
+    assert_eq!(Temp::from_repr(-1), Some(Temp::Cold));
+    assert_eq!(Temp::from_repr(0), Some(Temp::Zero));
+    assert_eq!(Temp::from_repr(1), Some(Temp::Hot));
+    assert_eq!(Temp::from_repr(2), None);
+}
+
 #[test]
 fn test_from_repr_with_unknown() {
     use macrokit::FromReprWithUnknown;
@@ -23,7 +41,8 @@ fn test_from_repr_with_unknown() {
     pub enum Status {
         Active = 0,
         Inactive = 1,
-        Unknown, // This variant is required
+        #[fallback]
+        Unknown,
     }
 
     let status_active: Status = 0u8.into();
@@ -36,6 +55,182 @@ fn test_from_repr_with_unknown() {
     assert_eq!(status_unknown, Status::Unknown);
 }
 
+#[test]
+fn test_from_repr_with_data_carrying_variants() {
+    use macrokit::{FromReprAsOption, FromReprWithUnknown};
+
+    #[derive(Debug, PartialEq, FromReprAsOption)]
+    #[repr(u8)]
+    pub enum Frame {
+        Ping = 0,
+        Payload(u32),
+    }
+
+    assert_eq!(Frame::from_repr(0), Some(Frame::Ping));
+    assert_eq!(Frame::from_repr(1), Some(Frame::Payload(0)));
+    assert_eq!(Frame::from_repr(2), None);
+
+    #[derive(Debug, PartialEq, FromReprWithUnknown)]
+    #[repr(u8)]
+    pub enum Message {
+        Ack = 0,
+        Data { len: u16 },
+        #[fallback]
+        Unknown,
+    }
+
+    let message_data: Message = 1u8.into();
+    assert_eq!(message_data, Message::Data { len: 0 });
+
+    let message_unknown: Message = 99u8.into();
+    assert_eq!(message_unknown, Message::Unknown);
+}
+
+#[test]
+fn test_from_repr_infers_repr_type_without_attribute() {
+    use macrokit::{FromReprAsOption, FromReprWithUnknown};
+
+    // No `#[repr(...)]`: every discriminant fits in a u8, so `from_repr`
+    // is inferred to take a `u8`.
+    #[derive(Debug, PartialEq, FromReprAsOption)]
+    pub enum SmallCode {
+        A = 0,
+        B = 1,
+    }
+
+    assert_eq!(SmallCode::from_repr(0u8), Some(SmallCode::A));
+    assert_eq!(SmallCode::from_repr(2u8), None);
+
+    // 300 doesn't fit in a u8, so the inferred type widens to u16.
+    #[derive(Debug, PartialEq, FromReprWithUnknown)]
+    pub enum WideCode {
+        Low = 0,
+        High = 300,
+        #[fallback]
+        Unknown,
+    }
+
+    let high: WideCode = 300u16.into();
+    assert_eq!(high, WideCode::High);
+}
+
+#[test]
+fn test_from_repr_infers_signed_repr_type_for_negative_discriminant() {
+    use macrokit::FromReprAsOption;
+
+    // -200 doesn't fit in an i8, so the inferred type widens to i16 rather
+    // than losing its sign and landing on a u8.
+    #[derive(Debug, PartialEq, FromReprAsOption)]
+    pub enum WideNegativeCode {
+        Low = -200,
+        High = 0,
+    }
+
+    assert_eq!(WideNegativeCode::from_repr(-200i16), Some(WideNegativeCode::Low));
+    assert_eq!(WideNegativeCode::from_repr(0i16), Some(WideNegativeCode::High));
+}
+
+#[test]
+fn test_into_repr() {
+    use macrokit::IntoRepr;
+
+    #[derive(Debug, PartialEq, IntoRepr)]
+    #[repr(u8)]
+    pub enum Command {
+        Read = 1,
+        Write = 2,
+    }
+
+    assert_eq!(Command::Read.as_repr(), 1);
+    assert_eq!(u8::from(Command::Write), 2);
+
+    #[derive(Debug, PartialEq, IntoRepr)]
+    #[repr(u8)]
+    pub enum Frame {
+        Ping = 0,
+        Payload(u32),
+    }
+
+    assert_eq!(Frame::Ping.as_repr(), 0);
+    assert_eq!(Frame::Payload(42).as_repr(), 1);
+}
+
+#[test]
+fn test_into_repr_with_negative_discriminant() {
+    use macrokit::IntoRepr;
+
+    #[derive(Debug, PartialEq, IntoRepr)]
+    #[repr(i8)]
+    pub enum Temp {
+        Cold = -1,
+        Zero = 0,
+        Hot = 1,
+    }
+
+    assert_eq!(Temp::Cold.as_repr(), -1);
+    assert_eq!(i8::from(Temp::Cold), -1);
+    assert_eq!(Temp::Hot.as_repr(), 1);
+}
+
+#[test]
+fn test_try_from_repr() {
+    use macrokit::TryFromRepr;
+
+    #[derive(Debug, PartialEq, TryFromRepr)]
+    #[repr(u8)]
+    pub enum Command {
+        Read = 1,
+        Write = 2,
+    }
+
+    assert_eq!(Command::try_from(1), Ok(Command::Read));
+    assert_eq!(Command::try_from(2), Ok(Command::Write));
+
+    let err = Command::try_from(3).unwrap_err();
+    assert_eq!(err.value, 3);
+    assert_eq!(err.to_string(), "invalid discriminant 3 for Command");
+}
+
+#[test]
+fn test_try_from_repr_with_negative_discriminant() {
+    use macrokit::TryFromRepr;
+
+    #[derive(Debug, PartialEq, TryFromRepr)]
+    #[repr(i8)]
+    pub enum Temp {
+        Cold = -1,
+        Zero = 0,
+        Hot = 1,
+    }
+
+    assert_eq!(Temp::try_from(-1), Ok(Temp::Cold));
+    assert_eq!(Temp::try_from(0), Ok(Temp::Zero));
+
+    let err = Temp::try_from(-2).unwrap_err();
+    assert_eq!(err.value, -2);
+    assert_eq!(err.to_string(), "invalid discriminant -2 for Temp");
+}
+
+#[test]
+fn test_try_from_repr_detects_distinct_negative_discriminants() {
+    use macrokit::TryFromRepr;
+
+    // `Cold` and `Freezing` must keep their distinct explicit discriminants
+    // rather than both resolving to whatever auto-increment miscomputed
+    // negative literals would otherwise collapse to.
+    #[derive(Debug, PartialEq, TryFromRepr)]
+    #[repr(i8)]
+    pub enum Temp {
+        Freezing = -2,
+        Cold = -1,
+        Zero = 0,
+    }
+
+    assert_eq!(Temp::try_from(-2), Ok(Temp::Freezing));
+    assert_eq!(Temp::try_from(-1), Ok(Temp::Cold));
+    assert_eq!(Temp::try_from(0), Ok(Temp::Zero));
+}
+
 #[test]
 fn ui() {
     let t = trybuild::TestCases::new();
@@ -0,0 +1,14 @@
+use macrokit::FromReprWithUnknown;
+
+#[derive(Debug, PartialEq, FromReprWithUnknown)]
+#[repr(u8)]
+pub enum Status {
+    Active = 0,
+    Inactive = 1,
+    #[fallback]
+    Unknown,
+    #[fallback]
+    Unsupported,
+}
+
+fn main() {}
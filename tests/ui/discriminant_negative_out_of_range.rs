@@ -0,0 +1,10 @@
+use macrokit::FromReprAsOption;
+
+#[derive(Debug, PartialEq, FromReprAsOption)]
+#[repr(i8)]
+pub enum Temp {
+    TooCold = -200,
+    Hot = 1,
+}
+
+fn main() {}
@@ -4,8 +4,10 @@ use macrokit::FromReprWithUnknown;
 #[repr(u8)]
 pub enum Status {
     Active = 0,
+    #[alternatives(300)]
     Inactive = 1,
-    // Missing the 'Unknown' variant
+    #[fallback]
+    Unknown,
 }
 
 fn main() {}
@@ -0,0 +1,14 @@
+use macrokit::FromReprWithUnknown;
+
+#[derive(Debug, PartialEq, FromReprWithUnknown)]
+#[repr(u8)]
+pub enum Status {
+    Active = 0,
+    Inactive = 1,
+    #[alternatives(1)]
+    Other = 2,
+    #[fallback]
+    Unknown,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use macrokit::FromReprWithUnknown;
+
+#[derive(Debug, PartialEq, FromReprWithUnknown)]
+#[repr(i8)]
+pub enum Status {
+    Freezing = -2,
+    #[alternatives(-1)]
+    Chilly = -3,
+    Cold = -1,
+    #[fallback]
+    Unknown,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use macrokit::FromReprWithUnknown;
+
+#[derive(Debug, PartialEq, FromReprWithUnknown)]
+#[repr(u8)]
+pub enum Status {
+    Active = 0,
+    Inactive = 1,
+    // Missing a variant marked `#[fallback]`
+    Unknown,
+}
+
+fn main() {}